@@ -0,0 +1,151 @@
+//! Wrapper types that round-trip date/time values through a single SQLite
+//! column, either as RFC 3339 `TEXT` or as an `INTEGER` Unix timestamp that
+//! stays queryable with SQLite's own `datetime()`/`date()`/`strftime()`
+//! functions.
+//!
+//! Enabled by the `chrono` and `time` cargo features, mirroring rusqlite's
+//! own `types/chrono.rs` and `types/time.rs`.
+//!
+//! On read, every wrapper here accepts both representations (SQLite is
+//! dynamically typed, so a column declared `TEXT` can still hold an
+//! `INTEGER` row and vice versa) regardless of which one it writes.
+
+#[cfg(feature = "chrono")]
+pub mod chrono {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::{de, ser};
+    use std::fmt;
+
+    /// Stores a `DateTime<Utc>` as RFC 3339 `TEXT`
+    /// (`%Y-%m-%dT%H:%M:%S%.fZ`).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SqlDateTime(pub DateTime<Utc>);
+
+    /// Like [`SqlDateTime`], but stores (and writes) a Unix timestamp
+    /// `INTEGER` instead of text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SqlTimestamp(pub DateTime<Utc>);
+
+    impl ser::Serialize for SqlDateTime {
+        fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0.format("%Y-%m-%dT%H:%M:%S%.fZ").to_string())
+        }
+    }
+
+    impl ser::Serialize for SqlTimestamp {
+        fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_i64(self.0.timestamp())
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for SqlDateTime {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(DateTimeVisitor).map(SqlDateTime)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for SqlTimestamp {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(DateTimeVisitor).map(SqlTimestamp)
+        }
+    }
+
+    struct DateTimeVisitor;
+
+    impl<'de> de::Visitor<'de> for DateTimeVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an RFC 3339 datetime string or a Unix timestamp")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<DateTime<Utc>, E> {
+            // SQLite's own `datetime()` omits fractional seconds and prefers
+            // a space over 'T'; accept both that and a strict RFC 3339
+            // "...Z"/"...+00:00" string.
+            let normalized = v.replacen(' ', "T", 1);
+            DateTime::parse_from_rfc3339(&normalized)
+                .map(|dt| dt.with_timezone(&Utc))
+                .or_else(|_| chrono::NaiveDateTime::parse_from_str(&normalized, "%Y-%m-%dT%H:%M:%S").map(|dt| Utc.from_utc_datetime(&dt)))
+                .map_err(de::Error::custom)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<DateTime<Utc>, E> {
+            Ok(Utc.timestamp(v, 0))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<DateTime<Utc>, E> {
+            Ok(Utc.timestamp(v as i64, 0))
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+pub mod time {
+    use serde::{de, ser};
+    use std::fmt;
+    use time::{OffsetDateTime, PrimitiveDateTime};
+
+    /// Stores an `OffsetDateTime` as RFC 3339 `TEXT`, always normalized to
+    /// UTC.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SqlDateTime(pub OffsetDateTime);
+
+    /// Like [`SqlDateTime`], but stores (and writes) a Unix timestamp
+    /// `INTEGER` instead of text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SqlTimestamp(pub OffsetDateTime);
+
+    impl ser::Serialize for SqlDateTime {
+        fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0.to_offset(time::UtcOffset::UTC).format("%Y-%m-%dT%H:%M:%S.%NZ"))
+        }
+    }
+
+    impl ser::Serialize for SqlTimestamp {
+        fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_i64(self.0.unix_timestamp())
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for SqlDateTime {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(DateTimeVisitor).map(SqlDateTime)
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for SqlTimestamp {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(DateTimeVisitor).map(SqlTimestamp)
+        }
+    }
+
+    struct DateTimeVisitor;
+
+    impl<'de> de::Visitor<'de> for DateTimeVisitor {
+        type Value = OffsetDateTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an RFC 3339 datetime string or a Unix timestamp")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<OffsetDateTime, E> {
+            let normalized = v.replacen(' ', "T", 1);
+            if let Ok(dt) = OffsetDateTime::parse(&normalized, "%Y-%m-%dT%H:%M:%S.%NZ") {
+                return Ok(dt);
+            }
+            // No fractional seconds, as SQLite's `datetime()` produces.
+            PrimitiveDateTime::parse(&normalized, "%Y-%m-%dT%H:%M:%S")
+                .map(|dt| dt.assume_utc())
+                .map_err(de::Error::custom)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<OffsetDateTime, E> {
+            Ok(OffsetDateTime::from_unix_timestamp(v))
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<OffsetDateTime, E> {
+            Ok(OffsetDateTime::from_unix_timestamp(v as i64))
+        }
+    }
+}
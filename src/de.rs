@@ -0,0 +1,618 @@
+use std::marker::PhantomData;
+
+use rusqlite::types::ValueRef;
+use rusqlite::{Row, Rows};
+use serde::de::{self, Deserialize, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+
+use super::{Columns, Error, ErrorKind, Result};
+
+/// Deserializes a single `rusqlite` row into any `D: Deserialize`, resolving
+/// struct/map fields against `columns` (see
+/// [`columns_from_statement`](super::columns_from_statement)).
+pub struct Deserializer<'a, 'stmt: 'a> {
+    row: &'a Row<'stmt>,
+    columns: &'a Columns,
+}
+
+/// Deserialize a single row into `D`.
+pub fn from_row<'de, D: Deserialize<'de>>(row: &Row, columns: &Columns) -> Result<D> {
+    D::deserialize(Deserializer { row, columns })
+}
+
+/// Deserialize every row of `rows` into a `D`, consuming the iterator.
+///
+/// Panics (rather than returning a `Result`) if a row fails to deserialize,
+/// which keeps read-heavy call sites that would just `.unwrap()` anyway
+/// free of boilerplate.
+pub fn from_rows<'a, 'stmt, D: DeserializeOwned>(rows: Rows<'stmt>, columns: &'a Columns) -> RowsIter<'a, 'stmt, D> {
+    RowsIter { rows, columns, marker: PhantomData }
+}
+
+/// Like [`from_rows`], but borrows `rows` instead of taking ownership of it.
+pub fn from_rows_ref<'a, 'r, 'stmt, D: DeserializeOwned>(rows: &'r mut Rows<'stmt>, columns: &'a Columns) -> RowsRefIter<'a, 'r, 'stmt, D> {
+    RowsRefIter { rows, columns, marker: PhantomData }
+}
+
+/// Iterator returned by [`from_rows`].
+pub struct RowsIter<'a, 'stmt, D> {
+    rows: Rows<'stmt>,
+    columns: &'a Columns,
+    marker: PhantomData<D>,
+}
+
+impl<'a, 'stmt, D: DeserializeOwned> Iterator for RowsIter<'a, 'stmt, D> {
+    type Item = D;
+
+    fn next(&mut self) -> Option<D> {
+        match self.rows.next() {
+            Some(Ok(row)) => Some(from_row(&row, self.columns).expect("failed to deserialize row")),
+            Some(Err(e)) => panic!("failed to fetch row: {}", e),
+            None => None,
+        }
+    }
+}
+
+/// Iterator returned by [`from_rows_ref`].
+pub struct RowsRefIter<'a, 'r, 'stmt, D> {
+    rows: &'r mut Rows<'stmt>,
+    columns: &'a Columns,
+    marker: PhantomData<D>,
+}
+
+impl<'a, 'r, 'stmt, D: DeserializeOwned> Iterator for RowsRefIter<'a, 'r, 'stmt, D> {
+    type Item = D;
+
+    fn next(&mut self) -> Option<D> {
+        match self.rows.next() {
+            Some(Ok(row)) => Some(from_row(&row, self.columns).expect("failed to deserialize row")),
+            Some(Err(e)) => panic!("failed to fetch row: {}", e),
+            None => None,
+        }
+    }
+}
+
+/// Deserializer for a single column, shared by the row-level `Deserializer`
+/// (for scalar/tuple/seq access) and `RowMapAccess` (for struct/map access).
+struct ValueDeserializer<'a, 'stmt: 'a> {
+    row: &'a Row<'stmt>,
+    column: usize,
+    column_name: &'a str,
+}
+
+impl<'a, 'stmt: 'a> ValueDeserializer<'a, 'stmt> {
+    fn value(&self) -> ValueRef<'stmt> {
+        self.row.get_raw(self.column)
+    }
+
+    fn integer(&self) -> Result<i64> {
+        match self.value() {
+            ValueRef::Integer(v) => Ok(v),
+            other => Err(ErrorKind::Deserialization(format!("column \"{}\" is not an integer: {:?}", self.column_name, other)).into()),
+        }
+    }
+
+    /// Like [`integer`](Self::integer), but checks that the stored `i64`
+    /// actually fits `min..=max` instead of silently truncating, mirroring
+    /// rusqlite's `IntegralValueOutOfRange` guard on the `FromSql` side.
+    fn ranged_integer(&self, min: i64, max: i64) -> Result<i64> {
+        let v = self.integer()?;
+        if v < min || v > max {
+            return Err(ErrorKind::IntegralValueOutOfRange(self.column_name.to_owned(), v).into());
+        }
+        Ok(v)
+    }
+}
+
+macro_rules! deserialize_ranged_integer {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+            visitor.$visit(self.ranged_integer(<$ty>::min_value() as i64, <$ty>::max_value() as i64)? as $ty)
+        }
+    };
+}
+
+impl<'de, 'a, 'stmt: 'a> de::Deserializer<'de> for ValueDeserializer<'a, 'stmt> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value() {
+            ValueRef::Null => visitor.visit_unit(),
+            ValueRef::Integer(v) => visitor.visit_i64(v),
+            ValueRef::Real(v) => visitor.visit_f64(v),
+            ValueRef::Text(_) => self.deserialize_str(visitor),
+            ValueRef::Blob(_) => self.deserialize_bytes(visitor),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_bool(self.integer()? != 0)
+    }
+
+    deserialize_ranged_integer!(deserialize_i8, visit_i8, i8);
+    deserialize_ranged_integer!(deserialize_i16, visit_i16, i16);
+    deserialize_ranged_integer!(deserialize_i32, visit_i32, i32);
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i64(self.integer()?)
+    }
+
+    deserialize_ranged_integer!(deserialize_u8, visit_u8, u8);
+    deserialize_ranged_integer!(deserialize_u16, visit_u16, u16);
+    deserialize_ranged_integer!(deserialize_u32, visit_u32, u32);
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u64(self.ranged_integer(0, i64::max_value())? as u64)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value() {
+            ValueRef::Real(v) => visitor.visit_f32(v as f32),
+            ValueRef::Integer(v) => visitor.visit_f32(v as f32),
+            other => Err(ErrorKind::Deserialization(format!("column \"{}\" is not a real: {:?}", self.column_name, other)).into()),
+        }
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value() {
+            ValueRef::Real(v) => visitor.visit_f64(v),
+            ValueRef::Integer(v) => visitor.visit_f64(v as f64),
+            other => Err(ErrorKind::Deserialization(format!("column \"{}\" is not a real: {:?}", self.column_name, other)).into()),
+        }
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let s = self.value().as_str().map_err(|e| Error::from(ErrorKind::Deserialization(e.to_string())))?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(ErrorKind::Deserialization(format!("column \"{}\" does not hold a single character", self.column_name)).into()),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        let s = self.value().as_str().map_err(|e| Error::from(ErrorKind::Deserialization(e.to_string())))?;
+        visitor.visit_string(s.to_owned())
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value() {
+            ValueRef::Blob(b) => visitor.visit_byte_buf(b.to_owned()),
+            ValueRef::Text(t) => visitor.visit_byte_buf(t.to_owned()),
+            other => Err(ErrorKind::Deserialization(format!("column \"{}\" is not a blob: {:?}", self.column_name, other)).into()),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value() {
+            ValueRef::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        let s = self.value().as_str().map_err(|e| Error::from(ErrorKind::Deserialization(e.to_string())))?;
+        visitor.visit_enum(s.to_owned().into_deserializer())
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(ErrorKind::Deserialization(format!("column \"{}\" cannot be deserialized as a sequence", self.column_name)).into())
+    }
+
+    serde::forward_to_deserialize_any! {
+        map struct tuple tuple_struct struct_variant identifier ignored_any
+    }
+}
+
+impl<'a, 'stmt: 'a> Deserializer<'a, 'stmt> {
+    fn column(&self, index: usize) -> ValueDeserializer<'a, 'stmt> {
+        ValueDeserializer { row: self.row, column: index, column_name: &self.columns[index] }
+    }
+}
+
+impl<'de, 'a, 'stmt: 'a> de::Deserializer<'de> for Deserializer<'a, 'stmt> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, _fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        visitor.visit_map(RowMapAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(RowMapAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(RowSeqAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(self, _name: &'static str, len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(RowSeqAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, name: &'static str, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_newtype_struct(name, visitor)
+    }
+
+    // Delegated explicitly (rather than via `forward_to_deserialize_any!`) so
+    // that a bare scalar read (e.g. `from_row::<u8>`) goes through
+    // `ValueDeserializer`'s range-checked integer methods instead of
+    // `deserialize_any`'s unchecked `visit_i64`.
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_i8(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_i16(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_i32(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u8(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u16(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u32(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u64(visitor)
+    }
+
+    // Likewise: `deserialize_any` visits SQLite's `Integer` storage class as
+    // `visit_i64`, which serde's bool visitor rejects outright, breaking a
+    // bare `from_row::<bool>` round trip.
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_bool(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        i64 f32 f64 char str string
+        bytes byte_buf option unit unit_struct enum identifier ignored_any
+    }
+}
+
+/// `MapAccess` over a row's columns, used to deserialize structs and maps.
+struct RowMapAccess<'d, 'a: 'd, 'stmt: 'a> {
+    deserializer: &'d Deserializer<'a, 'stmt>,
+    index: usize,
+}
+
+impl<'de, 'd, 'a: 'd, 'stmt: 'a> de::MapAccess<'de> for RowMapAccess<'d, 'a, 'stmt> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.index >= self.deserializer.columns.len() {
+            return Ok(None);
+        }
+        let name: &'a str = &self.deserializer.columns[self.index];
+        seed.deserialize(name.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = seed.deserialize(self.deserializer.column(self.index))?;
+        self.index += 1;
+        Ok(value)
+    }
+}
+
+/// `SeqAccess` over a row's columns, used to deserialize tuples and
+/// sequences.
+struct RowSeqAccess<'d, 'a: 'd, 'stmt: 'a> {
+    deserializer: &'d Deserializer<'a, 'stmt>,
+    index: usize,
+}
+
+impl<'de, 'd, 'a: 'd, 'stmt: 'a> de::SeqAccess<'de> for RowSeqAccess<'d, 'a, 'stmt> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.index >= self.deserializer.columns.len() {
+            return Ok(None);
+        }
+        let value = seed.deserialize(self.deserializer.column(self.index))?;
+        self.index += 1;
+        Ok(Some(value))
+    }
+}
+
+/// Deserialize `row` directly from the `ValueRef`s it holds, without
+/// allocating owned `String`/`Vec<u8>` storage for TEXT/BLOB columns whose
+/// target field borrows instead (e.g. `&str`, `&[u8]`, `#[serde(borrow)]`).
+///
+/// `ValueRef` borrows straight from SQLite's statement buffer, so the
+/// deserialized value cannot outlive the statement that produced `row`;
+/// `columns` is required to live that long too since struct/map field names
+/// are themselves borrowed.
+pub fn from_row_ref_borrowed<'a, 'stmt, D: Deserialize<'stmt>>(row: &'a Row<'stmt>, columns: &'stmt Columns) -> Result<D> {
+    D::deserialize(BorrowedDeserializer { row, columns })
+}
+
+/// A single column's value, deserialized by borrowing its `ValueRef`
+/// directly instead of allocating. Reuses [`ValueDeserializer`]'s non-string
+/// methods and only overrides the TEXT/BLOB paths.
+struct BorrowedValueDeserializer<'a, 'stmt: 'a>(ValueDeserializer<'a, 'stmt>);
+
+impl<'a, 'stmt: 'a> de::Deserializer<'stmt> for BorrowedValueDeserializer<'a, 'stmt> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        match self.0.value() {
+            ValueRef::Text(_) => self.deserialize_str(visitor),
+            ValueRef::Blob(_) => self.deserialize_bytes(visitor),
+            _ => self.0.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        let s = self.0.value().as_str().map_err(|e| Error::from(ErrorKind::Deserialization(e.to_string())))?;
+        visitor.visit_borrowed_str(s)
+    }
+
+    fn deserialize_string<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        match self.0.value() {
+            ValueRef::Blob(b) => visitor.visit_borrowed_bytes(b),
+            ValueRef::Text(t) => visitor.visit_borrowed_bytes(t),
+            other => Err(ErrorKind::Deserialization(format!("column \"{}\" is not a blob: {:?}", self.0.column_name, other)).into()),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        match self.0.value() {
+            ValueRef::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_bool(visitor)
+    }
+
+    fn deserialize_i8<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_i8(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_i16(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_i32(visitor)
+    }
+
+    fn deserialize_i64<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_u8(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_u16(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_u32(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_u64(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_f32(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_f64(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_char(visitor)
+    }
+
+    fn deserialize_unit<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_unit(visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'stmt>>(self, name: &'static str, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'stmt>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'stmt>>(self, name: &'static str, variants: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.0.deserialize_seq(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        map struct tuple tuple_struct struct_variant identifier ignored_any
+    }
+}
+
+/// Deserializes an entire row directly from the `ValueRef`s it holds; the
+/// borrowing counterpart of [`Deserializer`], used by
+/// [`from_row_ref_borrowed`].
+///
+/// Unlike [`Deserializer`], `columns` is tied to `'stmt` rather than to an
+/// arbitrary shorter lifetime, since struct/map field names are themselves
+/// borrowed out through `visit_borrowed_str`-compatible deserializers.
+struct BorrowedDeserializer<'a, 'stmt: 'a> {
+    row: &'a Row<'stmt>,
+    columns: &'stmt Columns,
+}
+
+impl<'a, 'stmt: 'a> BorrowedDeserializer<'a, 'stmt> {
+    fn column(&self, index: usize) -> BorrowedValueDeserializer<'a, 'stmt> {
+        BorrowedValueDeserializer(ValueDeserializer { row: self.row, column: index, column_name: &self.columns[index] })
+    }
+}
+
+impl<'a, 'stmt: 'a> de::Deserializer<'stmt> for BorrowedDeserializer<'a, 'stmt> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'stmt>>(self, _name: &'static str, _fields: &'static [&'static str], visitor: V) -> Result<V::Value> {
+        visitor.visit_map(BorrowedRowMapAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_map<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(BorrowedRowMapAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_tuple<V: Visitor<'stmt>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(BorrowedRowSeqAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'stmt>>(self, _name: &'static str, len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(BorrowedRowSeqAccess { deserializer: &self, index: 0 })
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'stmt>>(self, name: &'static str, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_newtype_struct(name, visitor)
+    }
+
+    // See the identical delegation on `Deserializer`: without this, a bare
+    // scalar read would bypass `ValueDeserializer`'s range-checked integer
+    // methods via `deserialize_any`.
+    fn deserialize_i8<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_i8(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_i16(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_i32(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u8(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u16(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u32(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_u64(visitor)
+    }
+
+    // See the identical delegation on `Deserializer`: without this, a bare
+    // `bool` read would bypass `ValueDeserializer::deserialize_bool` via
+    // `deserialize_any`'s unchecked `visit_i64`, which serde's bool visitor
+    // rejects.
+    fn deserialize_bool<V: Visitor<'stmt>>(self, visitor: V) -> Result<V::Value> {
+        self.column(0).deserialize_bool(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        i64 f32 f64 char str string
+        bytes byte_buf option unit unit_struct enum identifier ignored_any
+    }
+}
+
+/// `MapAccess` over a row's columns, producing borrowing column
+/// deserializers. The borrowing counterpart of [`RowMapAccess`].
+struct BorrowedRowMapAccess<'d, 'a: 'd, 'stmt: 'a> {
+    deserializer: &'d BorrowedDeserializer<'a, 'stmt>,
+    index: usize,
+}
+
+impl<'d, 'a: 'd, 'stmt: 'a> de::MapAccess<'stmt> for BorrowedRowMapAccess<'d, 'a, 'stmt> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'stmt>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.index >= self.deserializer.columns.len() {
+            return Ok(None);
+        }
+        let name: &'stmt str = &self.deserializer.columns[self.index];
+        seed.deserialize(de::value::BorrowedStrDeserializer::new(name)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'stmt>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = seed.deserialize(self.deserializer.column(self.index))?;
+        self.index += 1;
+        Ok(value)
+    }
+}
+
+/// `SeqAccess` over a row's columns, producing borrowing column
+/// deserializers. The borrowing counterpart of [`RowSeqAccess`].
+struct BorrowedRowSeqAccess<'d, 'a: 'd, 'stmt: 'a> {
+    deserializer: &'d BorrowedDeserializer<'a, 'stmt>,
+    index: usize,
+}
+
+impl<'d, 'a: 'd, 'stmt: 'a> de::SeqAccess<'stmt> for BorrowedRowSeqAccess<'d, 'a, 'stmt> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'stmt>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.index >= self.deserializer.columns.len() {
+            return Ok(None);
+        }
+        let value = seed.deserialize(self.deserializer.column(self.index))?;
+        self.index += 1;
+        Ok(Some(value))
+    }
+}
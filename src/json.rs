@@ -0,0 +1,41 @@
+use serde::{de, ser};
+use serde_json;
+
+/// Stores `T` as a single SQLite `TEXT` column holding its JSON
+/// representation, so that compound values (sequences, maps, nested
+/// structs) can live in a column that would otherwise require a scalar
+/// value.
+///
+/// The wrapper is opt-in on purpose: a plain `String` field is never
+/// mistaken for JSON, only a field explicitly typed as `JsonColumn<T>` is
+/// serialized/deserialized through `serde_json`.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Post {
+///     title: String,
+///     tags: JsonColumn<Vec<String>>,
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JsonColumn<T>(pub T);
+
+impl<T> From<T> for JsonColumn<T> {
+    fn from(value: T) -> Self {
+        JsonColumn(value)
+    }
+}
+
+impl<T: ser::Serialize> ser::Serialize for JsonColumn<T> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = serde_json::to_string(&self.0).map_err(ser::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+}
+
+impl<'de, T: de::Deserialize<'de>> de::Deserialize<'de> for JsonColumn<T> {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let json = String::deserialize(deserializer)?;
+        serde_json::from_str(&json).map(JsonColumn).map_err(de::Error::custom)
+    }
+}
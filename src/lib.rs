@@ -0,0 +1,106 @@
+//! Serialize and deserialize `rusqlite` rows and parameters to and from any
+//! type that implements `serde::Serialize` / `serde::Deserialize`.
+//!
+//! The typical flow is:
+//!
+//!   - build parameters for an `INSERT`/`UPDATE` with [`to_params`] or
+//!     [`to_params_named`],
+//!   - read the resulting rows back into your type with [`from_row`],
+//!     [`from_rows`] or [`from_rows_ref`].
+//!
+//! Since `rusqlite` rows don't expose enough information on their own to map
+//! columns back onto out-of-order struct fields, callers first compute a
+//! [`Columns`] list from the `Statement` with [`columns_from_statement`] and
+//! pass it alongside the row.
+
+extern crate rusqlite;
+extern crate serde;
+extern crate serde_json;
+#[macro_use]
+extern crate error_chain;
+
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "time")]
+extern crate time;
+
+#[cfg(test)]
+extern crate serde_bytes;
+#[cfg(test)]
+#[macro_use]
+extern crate serde_derive;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod datetime;
+mod de;
+mod json;
+mod ser;
+
+#[cfg(test)]
+mod tests;
+
+use rusqlite::Statement;
+
+pub use de::{from_row, from_row_ref_borrowed, from_rows, from_rows_ref, Deserializer};
+pub use json::JsonColumn;
+pub use ser::{to_params, to_params_for_statement, to_params_named, Params, Serializer};
+
+error_chain! {
+    errors {
+        /// A `u64` value didn't fit in SQLite's `i64` INTEGER storage class.
+        ValueTooLarge(v: u64) {
+            description("value too large to store in an SQLite INTEGER column")
+            display("value too large to store in an SQLite INTEGER column: {}", v)
+        }
+        /// An `i64` column value didn't fit in the integer type requested by
+        /// the deserializer.
+        IntegralValueOutOfRange(column: String, value: i64) {
+            description("integral value out of range for the requested type")
+            display("value {} in column \"{}\" is out of range for the requested integer type", value, column)
+        }
+        /// A prepared statement placeholder had no matching field in the
+        /// value being serialized by [`to_params_for_statement`].
+        MissingField(name: String) {
+            description("no field found for a statement placeholder")
+            display("no field found for placeholder \"{}\"", name)
+        }
+        /// Error raised while serializing a value into `rusqlite` parameters.
+        Serialization(msg: String) {
+            description("error serializing value")
+            display("error serializing value: {}", msg)
+        }
+        /// Error raised while deserializing a `rusqlite` row into a value.
+        Deserialization(msg: String) {
+            description("error deserializing value")
+            display("error deserializing value: {}", msg)
+        }
+    }
+}
+
+impl serde::ser::Error for Error {
+    fn custom<T: ::std::fmt::Display>(msg: T) -> Self {
+        ErrorKind::Serialization(msg.to_string()).into()
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: ::std::fmt::Display>(msg: T) -> Self {
+        ErrorKind::Deserialization(msg.to_string()).into()
+    }
+}
+
+/// The column names of a prepared statement, in the order `rusqlite` will
+/// hand back their values in a `Row`.
+///
+/// Computed once with [`columns_from_statement`] and then reused across
+/// every row produced by that statement.
+pub type Columns = Vec<String>;
+
+/// Capture the column names of `stmt` for later use with [`from_row`] and
+/// friends.
+///
+/// This has to be done ahead of time because a `Row` only exposes its values
+/// positionally; the column names live on the `Statement` that produced it.
+pub fn columns_from_statement(stmt: &Statement) -> Columns {
+    stmt.column_names().into_iter().map(String::from).collect()
+}
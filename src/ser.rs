@@ -0,0 +1,368 @@
+use rusqlite::types::Value;
+use rusqlite::{Statement, ToSql};
+use serde::ser::{self, Serialize};
+
+use super::{Error, ErrorKind, Result};
+
+/// Positional parameters produced by [`to_params`](super::to_params), ready
+/// to be handed to `execute`/`query_map` and friends via [`Params::to_slice`].
+pub struct Params {
+    values: Vec<Value>,
+}
+
+impl Params {
+    /// Borrow the collected values as a slice of `&dyn ToSql`, suitable for
+    /// `Connection::execute`/`Statement::query_map`.
+    pub fn to_slice(&self) -> Vec<&ToSql> {
+        self.values.iter().map(|v| v as &ToSql).collect()
+    }
+}
+
+/// Named (`:field`) parameters produced by
+/// [`to_params_named`](super::to_params_named).
+pub struct NamedParams {
+    values: Vec<(String, Value)>,
+}
+
+impl NamedParams {
+    /// Borrow the collected values as a slice of `(&str, &dyn ToSql)` pairs,
+    /// suitable for `Connection::execute_named`/`Statement::query_map_named`.
+    pub fn to_slice(&self) -> Vec<(&str, &ToSql)> {
+        self.values.iter().map(|(name, v)| (name.as_str(), v as &ToSql)).collect()
+    }
+}
+
+/// Serialize `value` into positional parameters, in field declaration order.
+pub fn to_params<S: Serialize>(value: &S) -> Result<Params> {
+    let values = value.serialize(Serializer { named: false })?.into_values();
+    Ok(Params { values })
+}
+
+/// Serialize `value` into `:name`-keyed parameters.
+pub fn to_params_named<S: Serialize>(value: &S) -> Result<NamedParams> {
+    let values = value.serialize(Serializer { named: true })?.into_named_values()?;
+    Ok(NamedParams { values })
+}
+
+/// Serialize `value` and reorder the result to match the placeholder order
+/// `stmt` expects, so a struct can be bound to a statement whose `?N`/`:name`
+/// placeholders don't follow the struct's own field declaration order.
+///
+/// Named placeholders (`:name`, `@name`, `$name`) are matched against the
+/// field with that name; anonymous `?` and numbered `?N` placeholders take
+/// the field at that position in the serialized field order. Returns [`ErrorKind::MissingField`] if a
+/// placeholder has no matching field.
+pub fn to_params_for_statement<S: Serialize>(value: &S, stmt: &Statement) -> Result<Params> {
+    let named = value.serialize(Serializer { named: true })?.into_named_values()?;
+    let mut values = Vec::with_capacity(stmt.parameter_count());
+    for index in 1..=stmt.parameter_count() {
+        let value = match stmt.parameter_name(index) {
+            Some(placeholder) if !placeholder.starts_with('?') => {
+                let name = placeholder.trim_start_matches(|c| c == ':' || c == '@' || c == '$');
+                named
+                    .iter()
+                    .find(|(field, _)| field == name)
+                    .map(|(_, v)| v.clone())
+                    .ok_or_else(|| ErrorKind::MissingField(name.to_owned()))?
+            }
+            _ => named
+                .get(index - 1)
+                .map(|(_, v)| v.clone())
+                .ok_or_else(|| ErrorKind::MissingField(format!("?{}", index)))?,
+        };
+        values.push(value);
+    }
+    Ok(Params { values })
+}
+
+/// Outcome of serializing a single value with [`Serializer`]: either a lone
+/// scalar (a newtype/tuple-struct field, a map value, ...) or a named/unnamed
+/// collection of fields gathered from a struct, map, tuple or sequence.
+pub enum Output {
+    Value(Value),
+    Values(Vec<Value>),
+    NamedValues(Vec<(String, Value)>),
+}
+
+impl Output {
+    fn into_values(self) -> Vec<Value> {
+        match self {
+            Output::Value(v) => vec![v],
+            Output::Values(v) => v,
+            Output::NamedValues(v) => v.into_iter().map(|(_, v)| v).collect(),
+        }
+    }
+
+    fn into_named_values(self) -> Result<Vec<(String, Value)>> {
+        match self {
+            Output::NamedValues(v) => Ok(v),
+            _ => Err(ErrorKind::Serialization("value has no named fields to bind by name".into()).into()),
+        }
+    }
+}
+
+/// The top-level `serde::Serializer` used by [`to_params`] and
+/// [`to_params_named`]. `named` controls whether struct/map fields keep their
+/// names (for [`to_params_named`]) or are flattened to a plain value list
+/// (for [`to_params`]).
+#[derive(Clone, Copy)]
+pub struct Serializer {
+    named: bool,
+}
+
+macro_rules! serialize_as_integer {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, v: $ty) -> Result<Output> {
+            Ok(Output::Value(Value::Integer(v as i64)))
+        }
+    };
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Output;
+    type Error = Error;
+
+    type SerializeSeq = ValueList;
+    type SerializeTuple = ValueList;
+    type SerializeTupleStruct = ValueList;
+    type SerializeTupleVariant = ValueList;
+    type SerializeMap = FieldList;
+    type SerializeStruct = FieldList;
+    type SerializeStructVariant = FieldList;
+
+    fn serialize_bool(self, v: bool) -> Result<Output> {
+        Ok(Output::Value(Value::Integer(v as i64)))
+    }
+
+    serialize_as_integer!(serialize_i8, i8);
+    serialize_as_integer!(serialize_i16, i16);
+    serialize_as_integer!(serialize_i32, i32);
+    serialize_as_integer!(serialize_i64, i64);
+    serialize_as_integer!(serialize_u8, u8);
+    serialize_as_integer!(serialize_u16, u16);
+    serialize_as_integer!(serialize_u32, u32);
+
+    fn serialize_u64(self, v: u64) -> Result<Output> {
+        if v > i64::max_value() as u64 {
+            return Err(ErrorKind::ValueTooLarge(v).into());
+        }
+        Ok(Output::Value(Value::Integer(v as i64)))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Output> {
+        Ok(Output::Value(Value::Real(v as f64)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Output> {
+        Ok(Output::Value(Value::Real(v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Output> {
+        Ok(Output::Value(Value::Text(v.to_string())))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Output> {
+        Ok(Output::Value(Value::Text(v.to_owned())))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Output> {
+        Ok(Output::Value(Value::Blob(v.to_owned())))
+    }
+
+    fn serialize_none(self) -> Result<Output> {
+        Ok(Output::Value(Value::Null))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Output> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Output> {
+        Ok(Output::Value(Value::Null))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Output> {
+        Ok(Output::Value(Value::Text(name.to_owned())))
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Output> {
+        Ok(Output::Value(Value::Text(variant.to_owned())))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Output> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<Output> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ValueList> {
+        Ok(ValueList { values: Vec::with_capacity(len.unwrap_or(0)) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ValueList> {
+        Ok(ValueList { values: Vec::with_capacity(len) })
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<ValueList> {
+        Ok(ValueList { values: Vec::with_capacity(len) })
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, _index: u32, _variant: &'static str, len: usize) -> Result<ValueList> {
+        Ok(ValueList { values: Vec::with_capacity(len) })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<FieldList> {
+        Ok(FieldList { named: self.named, fields: Vec::with_capacity(len.unwrap_or(0)), next_key: None })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<FieldList> {
+        Ok(FieldList { named: self.named, fields: Vec::with_capacity(len), next_key: None })
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, _index: u32, _variant: &'static str, len: usize) -> Result<FieldList> {
+        Ok(FieldList { named: self.named, fields: Vec::with_capacity(len), next_key: None })
+    }
+}
+
+/// Collects a tuple/tuple-struct/sequence into a flat, positional list of
+/// [`Value`]s.
+#[doc(hidden)]
+pub struct ValueList {
+    values: Vec<Value>,
+}
+
+impl ser::SerializeSeq for ValueList {
+    type Ok = Output;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        self.values.push(value.serialize(Serializer { named: false })?.into_values().remove(0));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Output> {
+        Ok(Output::Values(self.values))
+    }
+}
+
+impl ser::SerializeTuple for ValueList {
+    type Ok = Output;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Output> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for ValueList {
+    type Ok = Output;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Output> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for ValueList {
+    type Ok = Output;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Output> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Collects a struct/map/struct-variant into a list of named [`Value`]s.
+#[doc(hidden)]
+pub struct FieldList {
+    named: bool,
+    fields: Vec<(String, Value)>,
+    next_key: Option<String>,
+}
+
+impl FieldList {
+    fn push(&mut self, name: String, value: Value) {
+        self.fields.push((name, value));
+    }
+}
+
+impl ser::SerializeStruct for FieldList {
+    type Ok = Output;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        let value = value.serialize(Serializer { named: false })?.into_values().remove(0);
+        self.push(key.to_owned(), value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Output> {
+        if self.named {
+            Ok(Output::NamedValues(self.fields))
+        } else {
+            Ok(Output::Values(self.fields.into_iter().map(|(_, v)| v).collect()))
+        }
+    }
+}
+
+impl ser::SerializeStructVariant for FieldList {
+    type Ok = Output;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<()> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Output> {
+        ser::SerializeStruct::end(self)
+    }
+}
+
+impl ser::SerializeMap for FieldList {
+    type Ok = Output;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        let key = match key.serialize(Serializer { named: false })?.into_values().remove(0) {
+            Value::Text(s) => s,
+            other => format!("{:?}", other),
+        };
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let key = self.next_key.take().expect("serialize_value called before serialize_key");
+        let value = value.serialize(Serializer { named: false })?.into_values().remove(0);
+        self.push(key, value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Output> {
+        if self.named {
+            Ok(Output::NamedValues(self.fields))
+        } else {
+            Ok(Output::Values(self.fields.into_iter().map(|(_, v)| v).collect()))
+        }
+    }
+}
@@ -81,6 +81,48 @@ fn test_uint() {
 	test_ser_err(&u64::max_value(), |err| matches!(*err, super::Error(super::ErrorKind::ValueTooLarge(_), _)));
 }
 
+#[test]
+fn test_integer_out_of_range() {
+	fn test_de_err<D, F>(db_type: &str, src: i64, err_check_fn: F)
+	where
+		D: serde::de::DeserializeOwned + Debug,
+		F: Fn(&super::Error) -> bool,
+	{
+		let con = make_connection_with_spec(&format!("test_column {}", db_type));
+		con.execute("INSERT INTO test(test_column) VALUES(?)", &[&src as &rusqlite::types::ToSql]).unwrap();
+		let mut stmt = con.prepare("SELECT * FROM test").unwrap();
+		let columns = super::columns_from_statement(&stmt);
+		let mut res = stmt.query_map(&[], |row| super::from_row::<D>(row, &columns)).unwrap();
+		match res.next().unwrap().unwrap() {
+			Err(e) => assert!(err_check_fn(&e), "Error raised was not of the correct type, got: {}", e),
+			Ok(v) => panic!("Error was not raised, got value: {:?}", v),
+		}
+	}
+
+	test_de_err::<u8, _>(
+		"INT CHECK(typeof(test_column) == 'integer')",
+		300,
+		|err| matches!(*err, super::Error(super::ErrorKind::IntegralValueOutOfRange(_, 300), _)),
+	);
+	test_de_err::<i8, _>(
+		"INT CHECK(typeof(test_column) == 'integer')",
+		-200,
+		|err| matches!(*err, super::Error(super::ErrorKind::IntegralValueOutOfRange(_, -200), _)),
+	);
+
+	// the range check must also apply to a struct field, not just a bare
+	// scalar row read
+	#[derive(Deserialize, Debug)]
+	struct Test {
+		test_column: u8,
+	}
+	test_de_err::<Test, _>(
+		"INT CHECK(typeof(test_column) == 'integer')",
+		300,
+		|err| matches!(*err, super::Error(super::ErrorKind::IntegralValueOutOfRange(_, 300), _)),
+	);
+}
+
 #[test]
 fn test_float() {
 	test_value_same("REAL CHECK(typeof(test_column) == 'real')", &0.3_f32);
@@ -271,3 +313,139 @@ fn test_struct() {
 		assert_eq!(res.next().unwrap(), src);
 	}
 }
+
+#[test]
+fn test_json_column() {
+	use super::JsonColumn;
+
+	test_value_same("TEXT CHECK(typeof(test_column) == 'text')", &JsonColumn(vec!["a".to_owned(), "b".to_owned(), "c".to_owned()]));
+
+	{
+		let mut map = collections::HashMap::<String, i64>::new();
+		map.insert("one".into(), 1);
+		map.insert("two".into(), 2);
+		test_value_same("TEXT CHECK(typeof(test_column) == 'text')", &JsonColumn(map));
+	}
+
+	{
+		let con = make_connection_with_spec("
+			name TEXT CHECK(typeof(name) == 'text'),
+			tags TEXT CHECK(typeof(tags) == 'text'),
+			address TEXT CHECK(typeof(address) == 'text')
+		");
+
+		#[derive(Deserialize, Serialize, Debug, PartialEq)]
+		struct Address {
+			city: String,
+			zip: String,
+		}
+
+		#[derive(Deserialize, Serialize, Debug, PartialEq)]
+		struct Test {
+			name: String,
+			tags: JsonColumn<Vec<String>>,
+			address: JsonColumn<Address>,
+		}
+
+		// serialization
+		let src = Test {
+			name: "the test".into(),
+			tags: JsonColumn(vec!["x".into(), "y".into()]),
+			address: JsonColumn(Address { city: "Nowhere".into(), zip: "00000".into() }),
+		};
+		con.execute_named("INSERT INTO test VALUES(:name, :tags, :address)", &super::to_params_named(&src).unwrap().to_slice()).unwrap();
+		// deserialization
+		let mut stmt = con.prepare("SELECT * FROM test").unwrap();
+		let columns = super::columns_from_statement(&stmt);
+		let mut res = stmt.query_map(&[], |row| super::from_row::<Test>(row, &columns)).unwrap();
+		assert_eq!(res.next().unwrap().unwrap().unwrap(), src);
+	}
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_datetime() {
+	use super::datetime::chrono::{SqlDateTime, SqlTimestamp};
+	use chrono::{TimeZone, Utc};
+
+	let dt = Utc.ymd(2020, 6, 15).and_hms(12, 30, 45);
+	test_value_same("TEXT CHECK(typeof(test_column) == 'text')", &SqlDateTime(dt));
+	test_value_same("INT CHECK(typeof(test_column) == 'integer')", &SqlTimestamp(dt));
+
+	// each wrapper's DateTimeVisitor must accept both representations:
+	// a TEXT column read through SqlTimestamp, and an INTEGER column read
+	// through SqlDateTime.
+	test_values("TEXT CHECK(typeof(test_column) == 'text')", &SqlDateTime(dt), &SqlTimestamp(dt));
+	test_values("INT CHECK(typeof(test_column) == 'integer')", &SqlTimestamp(dt), &SqlDateTime(dt));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_datetime() {
+	use super::datetime::time::{SqlDateTime, SqlTimestamp};
+	use time::OffsetDateTime;
+
+	// 2020-06-15T12:30:45Z
+	let dt = OffsetDateTime::from_unix_timestamp(1592224245);
+	test_value_same("TEXT CHECK(typeof(test_column) == 'text')", &SqlDateTime(dt));
+	test_value_same("INT CHECK(typeof(test_column) == 'integer')", &SqlTimestamp(dt));
+
+	// each wrapper's DateTimeVisitor must accept both representations:
+	// a TEXT column read through SqlTimestamp, and an INTEGER column read
+	// through SqlDateTime.
+	test_values("TEXT CHECK(typeof(test_column) == 'text')", &SqlDateTime(dt), &SqlTimestamp(dt));
+	test_values("INT CHECK(typeof(test_column) == 'integer')", &SqlTimestamp(dt), &SqlDateTime(dt));
+}
+
+#[test]
+fn test_to_params_for_statement() {
+	#[derive(Serialize)]
+	struct Test {
+		f_real: f64,
+		f_integer: i64,
+		f_text: String,
+	}
+
+	let con = make_connection_with_spec("
+		f_integer INT CHECK(typeof(f_integer) == 'integer'),
+		f_real REAL CHECK(typeof(f_real) == 'real'),
+		f_text TEXT CHECK(typeof(f_text) == 'text')
+	");
+	let src = Test { f_real: 12.5, f_integer: 7, f_text: "reordered".into() };
+
+	// the statement's placeholder order doesn't match the struct's field order
+	let mut stmt = con.prepare("INSERT INTO test(f_integer, f_real, f_text) VALUES(:f_integer, :f_real, :f_text)").unwrap();
+	let params = super::to_params_for_statement(&src, &stmt).unwrap();
+	stmt.execute(&params.to_slice()).unwrap();
+
+	let mut stmt = con.prepare("SELECT * FROM test").unwrap();
+	let mut rows = stmt.query(&[]).unwrap();
+	let row = rows.next().unwrap().unwrap();
+	assert_eq!(row.get::<_, i64>(0), 7);
+	assert_eq!(row.get::<_, f64>(1), 12.5);
+	assert_eq!(row.get::<_, String>(2), "reordered");
+}
+
+#[test]
+fn test_from_row_ref_borrowed() {
+	let con = make_connection_with_spec("
+		f_text TEXT CHECK(typeof(f_text) == 'text'),
+		f_blob BLOB CHECK(typeof(f_blob) == 'blob')
+	");
+
+	#[derive(Deserialize, Debug, PartialEq)]
+	struct Test<'a> {
+		f_text: &'a str,
+		#[serde(with = "serde_bytes")]
+		f_blob: &'a [u8],
+	}
+
+	con.execute("INSERT INTO test VALUES(?, ?)", &[&"borrowed" as &rusqlite::types::ToSql, &b"12345".to_vec() as &rusqlite::types::ToSql]).unwrap();
+
+	let mut stmt = con.prepare("SELECT * FROM test").unwrap();
+	let columns = super::columns_from_statement(&stmt);
+	let mut rows = stmt.query(&[]).unwrap();
+	let row = rows.next().unwrap().unwrap();
+	let value = super::from_row_ref_borrowed::<Test>(&row, &columns).unwrap();
+	assert_eq!(value, Test { f_text: "borrowed", f_blob: b"12345" });
+}